@@ -0,0 +1,65 @@
+//! 复制当前释义到剪贴板
+//!
+//! 默认使用系统剪贴板插件，但在配置了 `copy_cmd`（如 Wayland 下的
+//! `wl-copy`）时改为把文本通过子进程 stdin 写入该命令。
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+/// 复制完成后发往前端的确认事件
+const EVENT_COPIED: &str = "copy-result-done";
+
+/// 读取 `config.yaml` 中的 `copy_cmd` 字段（不存在时为 `None`）
+fn load_copy_cmd(app: &AppHandle) -> Option<String> {
+    let config_path = crate::llm::resolve_config_path(app).ok()?;
+    let config_file = crate::llm::load_config_file(&config_path).ok()?;
+    config_file.copy_cmd.filter(|cmd| !cmd.trim().is_empty())
+}
+
+/// 通过外部命令复制文本（把文本写入其 stdin），用于 `wl-copy` 等场景
+fn copy_via_command(cmd: &str, text: &str) -> Result<(), String> {
+    let mut parts = cmd.split_whitespace();
+    let program = parts.next().ok_or_else(|| "copy_cmd is empty".to_string())?;
+    let args: Vec<&str> = parts.collect();
+
+    let mut child = Command::new(program)
+        .args(&args)
+        .stdin(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to spawn copy_cmd '{}': {}", cmd, e))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(text.as_bytes())
+            .map_err(|e| format!("Failed to write to copy_cmd stdin: {}", e))?;
+    }
+
+    let status = child
+        .wait()
+        .map_err(|e| format!("Failed to wait for copy_cmd '{}': {}", cmd, e))?;
+
+    if !status.success() {
+        return Err(format!("copy_cmd '{}' exited with {}", cmd, status));
+    }
+
+    Ok(())
+}
+
+/// Tauri command: 将渲染后的释义文本写入剪贴板
+///
+/// 优先使用配置的 `copy_cmd` 外部命令，未配置时回退到内置剪贴板插件。
+#[tauri::command]
+pub async fn copy_result(text: String, app: AppHandle) -> Result<(), String> {
+    match load_copy_cmd(&app) {
+        Some(cmd) => copy_via_command(&cmd, &text)?,
+        None => app
+            .clipboard()
+            .write_text(text)
+            .map_err(|e| format!("Failed to write clipboard: {}", e))?,
+    }
+
+    let _ = app.emit(EVENT_COPIED, ());
+    Ok(())
+}
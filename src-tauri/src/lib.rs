@@ -2,8 +2,11 @@ use tauri::{Manager, WindowEvent};
 use std::fs::OpenOptions;
 use std::io::Write;
 
+mod clipboard;
 mod dictionary;
 mod llm;
+mod llm_cache;
+mod screen_capture;
 mod shortcuts;
 mod tray;
 
@@ -43,7 +46,10 @@ pub fn run() {
             dictionary::lookup_word,
             dictionary::search_words,
             llm::llm_query,
-            llm::get_llm_config
+            llm::llm_query_stream,
+            llm::get_llm_config,
+            llm::clear_cache,
+            clipboard::copy_result
         ])
         .setup(|app| {
             debug_log("Setup starting...");
@@ -81,6 +87,14 @@ pub fn run() {
                 debug_log("Shortcuts initialized successfully");
             }
 
+            // Initialize screen capture polling
+            debug_log("Initializing screen capture...");
+            if let Err(e) = screen_capture::init_screen_capture(handle) {
+                debug_log(&format!("ERROR: Failed to initialize screen capture: {}", e));
+            } else {
+                debug_log("Screen capture initialized successfully");
+            }
+
             debug_log("Setup completed");
 
             // Setup window close interception - hide instead of close
@@ -1,14 +1,26 @@
 //! LLM 模块 - 提供 LLM 回退查询功能
 
+use futures_util::StreamExt;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
+use std::sync::mpsc::{channel, RecvTimeoutError};
 use std::sync::Mutex;
-use tauri::Manager;
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{Emitter, Manager};
+
+/// 配置热重载事件：config.yaml 被成功重新加载后发往前端
+const EVENT_CONFIG_CHANGED: &str = "llm-config-changed";
+const EVENT_CONFIG_ERROR: &str = "llm-config-error";
+
+/// 文件变更去抖时间
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(300);
 
 /// LLM 配置
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct LlmConfig {
     pub api_base: String,
     pub api_key: String,
@@ -25,9 +37,64 @@ fn default_temperature() -> f32 { 0.3 }
 fn default_max_tokens() -> u32 { 2048 }
 fn default_timeout() -> u64 { 30 }
 
-#[derive(Debug, Deserialize)]
-struct ConfigFile {
-    llm: LlmConfig,
+/// 缓存相关配置
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub(crate) struct CacheConfig {
+    #[serde(default = "crate::llm_cache::default_ttl_secs")]
+    pub(crate) ttl_secs: u64,
+}
+
+/// 整个 `config.yaml` 的顶层结构，各模块共享同一份文件。
+///
+/// `llm` 是单一 provider 的语法糖，`providers` 是按优先级排列的 provider
+/// 列表；两者同时存在时 `providers` 优先。
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct ConfigFile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) llm: Option<LlmConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) providers: Option<Vec<LlmConfig>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) shortcuts: Option<crate::shortcuts::ShortcutsConfig>,
+    /// 外部复制命令（如 Wayland 下的 `wl-copy`），未设置则使用系统剪贴板
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) copy_cmd: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) cache: Option<CacheConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) screen_capture: Option<crate::screen_capture::ScreenCaptureConfig>,
+}
+
+impl ConfigFile {
+    /// 展开为有序的 provider 列表：`providers` 非空时使用它，否则把单个
+    /// `llm` 当作唯一 provider
+    fn provider_list(&self) -> Vec<LlmConfig> {
+        if let Some(providers) = &self.providers {
+            if !providers.is_empty() {
+                return providers.clone();
+            }
+        }
+        self.llm.clone().into_iter().collect()
+    }
+}
+
+/// 读取并解析 `config_path` 处的配置文件
+pub(crate) fn load_config_file(config_path: &PathBuf) -> Result<ConfigFile, String> {
+    if !config_path.exists() {
+        return Err(format!("Config file not found: {:?}", config_path));
+    }
+
+    let content = fs::read_to_string(config_path)
+        .map_err(|e| format!("Failed to read config: {}", e))?;
+
+    serde_yaml::from_str(&content).map_err(|e| format!("Failed to parse config: {}", e))
+}
+
+/// 将配置写回 `config_path`，用于持久化前端的修改（如快捷键）
+pub(crate) fn write_config_file(config_path: &PathBuf, config: &ConfigFile) -> Result<(), String> {
+    let content =
+        serde_yaml::to_string(config).map_err(|e| format!("Failed to serialize config: {}", e))?;
+    fs::write(config_path, content).map_err(|e| format!("Failed to write config: {}", e))
 }
 
 /// OpenAI 兼容的请求格式
@@ -37,6 +104,7 @@ struct ChatRequest {
     messages: Vec<ChatMessage>,
     temperature: f32,
     max_tokens: u32,
+    stream: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -55,83 +123,145 @@ struct ChatChoice {
     message: ChatMessage,
 }
 
+/// SSE 流式响应的单个 chunk（OpenAI 兼容格式）
+#[derive(Debug, Deserialize)]
+struct ChatStreamChunk {
+    choices: Vec<ChatStreamChoice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatStreamChoice {
+    delta: ChatStreamDelta,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ChatStreamDelta {
+    #[serde(default)]
+    content: Option<String>,
+}
+
+/// 发往前端的流式分片事件
+#[derive(Debug, Clone, Serialize)]
+struct LlmStreamChunkEvent {
+    stream_id: String,
+    chunk: String,
+}
+
+/// 流式查询结束事件
+#[derive(Debug, Clone, Serialize)]
+struct LlmStreamDoneEvent {
+    stream_id: String,
+    error: Option<String>,
+}
+
+/// 配置热重载失败事件：携带失败原因，供前端提示用户本次编辑未生效
+#[derive(Debug, Clone, Serialize)]
+struct LlmConfigErrorEvent {
+    message: String,
+}
+
+const EVENT_LLM_STREAM: &str = "llm-stream";
+const EVENT_LLM_STREAM_DONE: &str = "llm-stream-done";
+
+/// 一次成功的 LLM 查询结果
+#[derive(Debug, Clone, Serialize)]
+pub struct LlmQueryResult {
+    pub content: String,
+    /// 实际返回结果的 provider（`api_base`）
+    pub provider: String,
+    /// 是否命中本地缓存
+    pub cached: bool,
+}
+
 /// LLM 状态管理
 pub struct LlmState {
-    config: Mutex<Option<LlmConfig>>,
+    providers: Mutex<Vec<LlmConfig>>,
+    cache: crate::llm_cache::QueryCache,
     client: Client,
 }
 
 impl LlmState {
     pub fn new() -> Self {
         Self {
-            config: Mutex::new(None),
+            providers: Mutex::new(Vec::new()),
+            cache: crate::llm_cache::QueryCache::new(),
             client: Client::new(),
         }
     }
 
     /// 初始化配置
     pub fn init(&self, config_path: PathBuf) -> Result<(), String> {
-        if !config_path.exists() {
-            return Err(format!("Config file not found: {:?}", config_path));
-        }
+        let config_file = load_config_file(&config_path)?;
 
-        let content = fs::read_to_string(&config_path)
-            .map_err(|e| format!("Failed to read config: {}", e))?;
+        let ttl_secs = config_file
+            .cache
+            .as_ref()
+            .map(|c| c.ttl_secs)
+            .unwrap_or_else(crate::llm_cache::default_ttl_secs);
+        self.cache.init(ttl_secs);
 
-        let config_file: ConfigFile = serde_yaml::from_str(&content)
-            .map_err(|e| format!("Failed to parse config: {}", e))?;
-
-        let mut lock = self.config.lock().unwrap();
-        *lock = Some(config_file.llm);
+        let mut lock = self.providers.lock().unwrap();
+        *lock = config_file.provider_list();
         Ok(())
     }
 
-    /// 查询 LLM
-    pub async fn query(&self, word: &str) -> Result<String, String> {
+    /// 查询 LLM：先查缓存，未命中时按顺序尝试各 provider，第一个成功即返回
+    pub async fn query(&self, word: &str) -> Result<LlmQueryResult, String> {
         crate::debug_log(&format!("[LLM] Starting query for: {}", word));
 
-        let config = {
-            let lock = self.config.lock().unwrap();
-            lock.clone().ok_or("LLM not configured")?
-        };
-
-        crate::debug_log(&format!("[LLM] Config loaded - api_base: {}, model: {}, timeout: {}s",
-            config.api_base, config.model, config.timeout));
+        if let Some(cached) = self.cache.get(word) {
+            crate::debug_log(&format!("[LLM] Cache hit for: {}", word));
+            return Ok(LlmQueryResult {
+                content: cached.content,
+                provider: cached.provider,
+                cached: true,
+            });
+        }
 
-        let prompt = format!(
-            r#"请解释英语单词或短语 "{}"，返回 JSON 格式（不要包含 markdown 代码块标记）：
+        let providers = { self.providers.lock().unwrap().clone() };
+        if providers.is_empty() {
+            return Err("LLM not configured".to_string());
+        }
 
-{{
-  "phonetic_us": "美式音标，如无则为 null",
-  "phonetic_uk": "英式音标，如无则为 null",
-  "translations": [
-    {{ "pos": "词性（如 n. / v. / adj.）", "tranCn": "中文释义" }}
-  ],
-  "sentences": [
-    {{ "en": "英文例句", "cn": "中文翻译" }}
-  ],
-  "phrases": [
-    {{ "phrase": "短语", "meaning": "含义" }}
-  ],
-  "rememberMethod": "记忆技巧或词源说明，如无则为 null"
-}}
+        let prompt = build_prompt(word);
+        let mut last_err = "No provider available".to_string();
+
+        for (idx, config) in providers.iter().enumerate() {
+            crate::debug_log(&format!(
+                "[LLM] Trying provider #{} - api_base: {}, model: {}, timeout: {}s",
+                idx, config.api_base, config.model, config.timeout
+            ));
+
+            match self.query_provider(config, &prompt).await {
+                Ok(content) => {
+                    self.cache.put(word, &content, &config.api_base);
+                    return Ok(LlmQueryResult {
+                        content,
+                        provider: config.api_base.clone(),
+                        cached: false,
+                    });
+                }
+                Err(e) => {
+                    crate::debug_log(&format!("[LLM] Provider #{} failed: {}", idx, e));
+                    last_err = e;
+                }
+            }
+        }
 
-要求：
-1. translations 至少包含 1 个释义
-2. sentences 包含 2-3 个例句
-3. phrases 包含常用短语搭配（如有），否则为空数组
-4. 只返回 JSON，不要其他内容"#,
-            word
-        );
+        Err(format!("All providers failed: {}", last_err))
+    }
 
+    /// 对单个 provider 发起一次非流式请求
+    async fn query_provider(&self, config: &LlmConfig, prompt: &str) -> Result<String, String> {
         let request = ChatRequest {
             model: config.model.clone(),
             messages: vec![ChatMessage {
                 role: "user".to_string(),
-                content: prompt,
+                content: prompt.to_string(),
             }],
             temperature: config.temperature,
             max_tokens: config.max_tokens,
+            stream: false,
         };
 
         let url = format!("{}/chat/completions", config.api_base.trim_end_matches('/'));
@@ -145,10 +275,7 @@ impl LlmState {
             .json(&request)
             .send()
             .await
-            .map_err(|e| {
-                crate::debug_log(&format!("[LLM] Request error: {:?}", e));
-                format!("Request failed: {}", e)
-            })?;
+            .map_err(|e| format!("Request failed: {}", e))?;
 
         crate::debug_log(&format!("[LLM] Response status: {}", response.status()));
 
@@ -167,8 +294,197 @@ impl LlmState {
             .choices
             .first()
             .map(|c| c.message.content.clone())
-            .ok_or("No response from LLM".to_string())
+            .ok_or_else(|| "No response from LLM".to_string())
+    }
+
+    /// 流式查询 LLM：按顺序尝试各 provider 直到一个成功建立连接，随后把每个
+    /// SSE chunk 通过 `llm-stream` 事件转发给前端，结束时发出
+    /// `llm-stream-done`（`error` 为 `None` 表示正常结束）。一旦开始转发，
+    /// 中途失败不会再切换到下一个 provider。
+    pub async fn query_stream(
+        &self,
+        word: &str,
+        stream_id: &str,
+        app: &tauri::AppHandle,
+    ) -> Result<(), String> {
+        crate::debug_log(&format!("[LLM] Starting stream query for: {} (stream_id={})", word, stream_id));
+
+        let providers = { self.providers.lock().unwrap().clone() };
+        let result = self.connect_and_forward_stream(&providers, word, stream_id, app).await;
+
+        let error = result.as_ref().err().cloned();
+        let _ = app.emit(
+            EVENT_LLM_STREAM_DONE,
+            LlmStreamDoneEvent {
+                stream_id: stream_id.to_string(),
+                error: error.clone(),
+            },
+        );
+
+        match error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
     }
+
+    async fn connect_and_forward_stream(
+        &self,
+        providers: &[LlmConfig],
+        word: &str,
+        stream_id: &str,
+        app: &tauri::AppHandle,
+    ) -> Result<(), String> {
+        if providers.is_empty() {
+            return Err("LLM not configured".to_string());
+        }
+
+        let prompt = build_prompt(word);
+        let mut last_err = "No provider available".to_string();
+
+        for (idx, config) in providers.iter().enumerate() {
+            let request = ChatRequest {
+                model: config.model.clone(),
+                messages: vec![ChatMessage {
+                    role: "user".to_string(),
+                    content: prompt.clone(),
+                }],
+                temperature: config.temperature,
+                max_tokens: config.max_tokens,
+                stream: true,
+            };
+
+            let url = format!("{}/chat/completions", config.api_base.trim_end_matches('/'));
+            crate::debug_log(&format!("[LLM] Sending stream request to provider #{}: {}", idx, url));
+
+            match self.connect_stream(&url, config, &request).await {
+                Ok(response) => {
+                    return self.forward_stream(response, stream_id, app).await;
+                }
+                Err(e) => {
+                    crate::debug_log(&format!("[LLM] Stream provider #{} failed to connect: {}", idx, e));
+                    last_err = e;
+                }
+            }
+        }
+
+        Err(format!("All providers failed: {}", last_err))
+    }
+
+    /// 发起流式请求并校验响应状态，不读取响应体
+    async fn connect_stream(
+        &self,
+        url: &str,
+        config: &LlmConfig,
+        request: &ChatRequest,
+    ) -> Result<reqwest::Response, String> {
+        let response = self
+            .client
+            .post(url)
+            .header("Authorization", format!("Bearer {}", config.api_key))
+            .header("Content-Type", "application/json")
+            .timeout(std::time::Duration::from_secs(config.timeout))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(format!("API error {}: {}", status, text));
+        }
+
+        Ok(response)
+    }
+
+    /// 读取 SSE 响应体并逐个分片转发给前端
+    async fn forward_stream(
+        &self,
+        response: reqwest::Response,
+        stream_id: &str,
+        app: &tauri::AppHandle,
+    ) -> Result<(), String> {
+        let mut stream = response.bytes_stream();
+        // 累积原始字节而不是逐块转字符串：多字节 UTF-8 字符（本应用和 LLM
+        // 响应以中文为主）很可能恰好被网络分片边界切开，若每个分片独立
+        // 用 from_utf8_lossy 解码，被切开的两半都会各自变成无效序列，
+        // 产生乱码。只在按 `\n\n` 切出完整 SSE 事件后才解码一次。
+        let mut buf: Vec<u8> = Vec::new();
+
+        while let Some(item) = stream.next().await {
+            let bytes = item.map_err(|e| format!("Stream read error: {}", e))?;
+            buf.extend_from_slice(&bytes);
+
+            // SSE 事件以空行分隔
+            while let Some(pos) = buf.windows(2).position(|w| w == b"\n\n") {
+                let event_bytes: Vec<u8> = buf.drain(..pos + 2).collect();
+                let event = String::from_utf8_lossy(&event_bytes).into_owned();
+
+                for line in event.lines() {
+                    let Some(data) = line.strip_prefix("data: ").or_else(|| line.strip_prefix("data:")) else {
+                        continue;
+                    };
+                    let data = data.trim();
+
+                    if data == "[DONE]" {
+                        return Ok(());
+                    }
+
+                    let chunk: ChatStreamChunk = match serde_json::from_str(data) {
+                        Ok(c) => c,
+                        Err(e) => {
+                            crate::debug_log(&format!("[LLM] Failed to parse stream chunk: {} ({})", e, data));
+                            continue;
+                        }
+                    };
+
+                    if let Some(content) = chunk.choices.first().and_then(|c| c.delta.content.clone()) {
+                        if content.is_empty() {
+                            continue;
+                        }
+                        let _ = app.emit(
+                            EVENT_LLM_STREAM,
+                            LlmStreamChunkEvent {
+                                stream_id: stream_id.to_string(),
+                                chunk: content,
+                            },
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// 构造发给 LLM 的查词 prompt
+fn build_prompt(word: &str) -> String {
+    format!(
+        r#"请解释英语单词或短语 "{}"，返回 JSON 格式（不要包含 markdown 代码块标记）：
+
+{{
+  "phonetic_us": "美式音标，如无则为 null",
+  "phonetic_uk": "英式音标，如无则为 null",
+  "translations": [
+    {{ "pos": "词性（如 n. / v. / adj.）", "tranCn": "中文释义" }}
+  ],
+  "sentences": [
+    {{ "en": "英文例句", "cn": "中文翻译" }}
+  ],
+  "phrases": [
+    {{ "phrase": "短语", "meaning": "含义" }}
+  ],
+  "rememberMethod": "记忆技巧或词源说明，如无则为 null"
+}}
+
+要求：
+1. translations 至少包含 1 个释义
+2. sentences 包含 2-3 个例句
+3. phrases 包含常用短语搭配（如有），否则为空数组
+4. 只返回 JSON，不要其他内容"#,
+        word
+    )
 }
 
 /// 获取默认配置模板路径
@@ -249,26 +565,30 @@ llm:
     Ok(config_path)
 }
 
-/// 初始化 LLM
-pub fn init_llm(app: &tauri::AppHandle) -> Result<(), String> {
-    crate::debug_log("[LLM] Initializing...");
-
+/// 解析 `config.yaml` 的路径：开发模式下优先使用工作目录下的文件，
+/// 生产模式下使用（并在需要时创建）用户本地数据目录下的文件
+pub(crate) fn resolve_config_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
     // 开发模式：检查当前工作目录下的 config.yaml
     let dev_path = std::env::current_dir()
         .ok()
         .map(|p| p.join("src-tauri").join("config.yaml"));
 
-    let config_path = if let Some(ref path) = dev_path {
+    if let Some(ref path) = dev_path {
         if path.exists() {
             crate::debug_log(&format!("[LLM] Using dev config: {:?}", path));
-            path.clone()
-        } else {
-            // 生产模式：确保用户配置存在
-            ensure_config(app)?
+            return Ok(path.clone());
         }
-    } else {
-        ensure_config(app)?
-    };
+    }
+
+    // 生产模式：确保用户配置存在
+    ensure_config(app)
+}
+
+/// 初始化 LLM
+pub fn init_llm(app: &tauri::AppHandle) -> Result<(), String> {
+    crate::debug_log("[LLM] Initializing...");
+
+    let config_path = resolve_config_path(app)?;
 
     crate::debug_log(&format!("[LLM] Using config: {:?}", config_path));
 
@@ -279,37 +599,140 @@ pub fn init_llm(app: &tauri::AppHandle) -> Result<(), String> {
     })?;
 
     crate::debug_log("[LLM] Successfully initialized");
+
+    watch_config(app.clone(), config_path);
+
     Ok(())
 }
 
+/// 监听配置文件变化，实现设置热重载
+///
+/// 编辑器保存配置通常会触发多个事件（如先截断再写入），因此在一个
+/// `WATCH_DEBOUNCE` 窗口内合并多次事件，只重新加载一次。解析失败时保留
+/// 旧配置，不清空已生效的设置，同时发出 `llm-config-error` 事件告知前端
+/// 这次编辑没有生效。
+fn watch_config(app: tauri::AppHandle, config_path: PathBuf) {
+    thread::spawn(move || {
+        let (tx, rx) = channel::<notify::Result<Event>>();
+
+        let mut watcher = match notify::recommended_watcher(move |res| {
+            let _ = tx.send(res);
+        }) {
+            Ok(w) => w,
+            Err(e) => {
+                crate::debug_log(&format!("[LLM] Failed to create config watcher: {:?}", e));
+                return;
+            }
+        };
+
+        if let Err(e) = watcher.watch(&config_path, RecursiveMode::NonRecursive) {
+            crate::debug_log(&format!("[LLM] Failed to watch config file: {:?}", e));
+            return;
+        }
+
+        crate::debug_log(&format!("[LLM] Watching config for changes: {:?}", config_path));
+
+        let mut pending_since: Option<Instant> = None;
+
+        loop {
+            match rx.recv_timeout(Duration::from_millis(100)) {
+                Ok(Ok(event)) => {
+                    if matches!(event.kind, EventKind::Modify(_) | EventKind::Create(_)) {
+                        pending_since = Some(Instant::now());
+                    }
+                }
+                Ok(Err(e)) => {
+                    crate::debug_log(&format!("[LLM] Config watcher error: {:?}", e));
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+
+            if let Some(since) = pending_since {
+                if since.elapsed() >= WATCH_DEBOUNCE {
+                    pending_since = None;
+                    reload_config(&app, &config_path);
+                }
+            }
+        }
+    });
+}
+
+/// 重新加载配置文件，成功时发出 `llm-config-changed`，失败时发出
+/// `llm-config-error`（携带错误信息），前端两种情况都能得到反馈
+fn reload_config(app: &tauri::AppHandle, config_path: &PathBuf) {
+    crate::debug_log("[LLM] Config file changed, reloading...");
+
+    let state = app.state::<LlmState>();
+    match state.init(config_path.clone()) {
+        Ok(()) => {
+            crate::debug_log("[LLM] Config reloaded successfully");
+            let _ = app.emit(EVENT_CONFIG_CHANGED, ());
+        }
+        Err(e) => {
+            // 保留上一份有效配置，但要让用户知道这次编辑没有生效，
+            // 而不是只写进用户看不到的 debug 日志
+            crate::debug_log(&format!(
+                "[LLM] Config reload failed, keeping previous config: {}",
+                e
+            ));
+            let _ = app.emit(EVENT_CONFIG_ERROR, LlmConfigErrorEvent { message: e });
+        }
+    }
+}
+
 /// Tauri command: LLM 查询
 #[tauri::command]
-pub async fn llm_query(word: String, state: tauri::State<'_, LlmState>) -> Result<String, String> {
+pub async fn llm_query(
+    word: String,
+    state: tauri::State<'_, LlmState>,
+) -> Result<LlmQueryResult, String> {
     state.query(&word).await
 }
 
+/// Tauri command: 流式 LLM 查询，结果通过 `llm-stream` / `llm-stream-done` 事件推送
+#[tauri::command]
+pub async fn llm_query_stream(
+    word: String,
+    stream_id: String,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, LlmState>,
+) -> Result<(), String> {
+    state.query_stream(&word, &stream_id, &app).await
+}
+
+/// Tauri command: 清空 LLM 查询结果缓存
+#[tauri::command]
+pub fn clear_cache(state: tauri::State<'_, LlmState>) -> Result<(), String> {
+    state.cache.clear()
+}
+
 /// LLM 配置信息（用于前端显示）
 #[derive(Debug, Serialize)]
 pub struct LlmConfigInfo {
     pub api_base: String,
     pub model: String,
     pub configured: bool,
+    /// 已配置的 provider 数量
+    pub provider_count: usize,
 }
 
-/// Tauri command: 获取 LLM 配置信息
+/// Tauri command: 获取 LLM 配置信息（展示排在最前的 provider）
 #[tauri::command]
 pub fn get_llm_config(state: tauri::State<'_, LlmState>) -> LlmConfigInfo {
-    let lock = state.config.lock().unwrap();
-    match lock.as_ref() {
+    let providers = state.providers.lock().unwrap();
+    match providers.first() {
         Some(config) => LlmConfigInfo {
             api_base: config.api_base.clone(),
             model: config.model.clone(),
             configured: true,
+            provider_count: providers.len(),
         },
         None => LlmConfigInfo {
             api_base: String::new(),
             model: String::new(),
             configured: false,
+            provider_count: 0,
         },
     }
 }
@@ -0,0 +1,129 @@
+//! LLM 查询结果缓存
+//!
+//! 按小写单词把查询结果持久化到 `Dictyy` 数据目录下的一个 JSON 文件，
+//! 用于避免重复联网查询、降低延迟和 token 开销。带 TTL，过期条目视为未命中。
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const CACHE_FILE_NAME: &str = "llm_cache.json";
+
+/// 默认缓存有效期：1 天
+pub fn default_ttl_secs() -> u64 {
+    24 * 60 * 60
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntry {
+    pub content: String,
+    pub provider: String,
+    cached_at: u64,
+}
+
+/// LLM 查询结果缓存
+pub struct QueryCache {
+    path: Mutex<Option<PathBuf>>,
+    entries: Mutex<HashMap<String, CacheEntry>>,
+    ttl: Mutex<Duration>,
+}
+
+impl QueryCache {
+    pub fn new() -> Self {
+        Self {
+            path: Mutex::new(None),
+            entries: Mutex::new(HashMap::new()),
+            ttl: Mutex::new(Duration::from_secs(default_ttl_secs())),
+        }
+    }
+
+    /// 确定缓存文件路径、设置 TTL，并从磁盘加载已有条目
+    pub fn init(&self, ttl_secs: u64) {
+        *self.ttl.lock().unwrap() = Duration::from_secs(ttl_secs);
+
+        let Some(cache_dir) = dirs::data_local_dir().map(|d| d.join("Dictyy")) else {
+            crate::debug_log("[LLM] Cannot determine cache directory");
+            return;
+        };
+
+        if let Err(e) = fs::create_dir_all(&cache_dir) {
+            crate::debug_log(&format!("[LLM] Failed to create cache directory: {}", e));
+            return;
+        }
+
+        let cache_path = cache_dir.join(CACHE_FILE_NAME);
+
+        if cache_path.exists() {
+            match fs::read_to_string(&cache_path) {
+                Ok(content) => match serde_json::from_str::<HashMap<String, CacheEntry>>(&content) {
+                    Ok(loaded) => {
+                        crate::debug_log(&format!("[LLM] Loaded {} cache entries", loaded.len()));
+                        *self.entries.lock().unwrap() = loaded;
+                    }
+                    Err(e) => crate::debug_log(&format!("[LLM] Failed to parse cache file: {}", e)),
+                },
+                Err(e) => crate::debug_log(&format!("[LLM] Failed to read cache file: {}", e)),
+            }
+        }
+
+        *self.path.lock().unwrap() = Some(cache_path);
+    }
+
+    /// 查询未过期的缓存条目（key 使用小写单词）
+    pub fn get(&self, word: &str) -> Option<CacheEntry> {
+        let key = word.to_lowercase();
+        let ttl = *self.ttl.lock().unwrap();
+        let entries = self.entries.lock().unwrap();
+        let entry = entries.get(&key)?;
+
+        if now_secs().saturating_sub(entry.cached_at) > ttl.as_secs() {
+            return None;
+        }
+
+        Some(entry.clone())
+    }
+
+    /// 写入一条缓存并持久化到磁盘
+    pub fn put(&self, word: &str, content: &str, provider: &str) {
+        let entry = CacheEntry {
+            content: content.to_string(),
+            provider: provider.to_string(),
+            cached_at: now_secs(),
+        };
+
+        {
+            let mut entries = self.entries.lock().unwrap();
+            entries.insert(word.to_lowercase(), entry);
+        }
+
+        if let Err(e) = self.persist() {
+            crate::debug_log(&format!("[LLM] Failed to persist cache: {}", e));
+        }
+    }
+
+    /// 清空缓存（内存与磁盘）
+    pub fn clear(&self) -> Result<(), String> {
+        self.entries.lock().unwrap().clear();
+        self.persist()
+    }
+
+    fn persist(&self) -> Result<(), String> {
+        let path = self.path.lock().unwrap().clone();
+        let Some(path) = path else { return Ok(()) };
+
+        let entries = self.entries.lock().unwrap();
+        let content = serde_json::to_string_pretty(&*entries)
+            .map_err(|e| format!("Failed to serialize cache: {}", e))?;
+        fs::write(&path, content).map_err(|e| format!("Failed to write cache file: {}", e))
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
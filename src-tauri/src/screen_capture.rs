@@ -1,6 +1,9 @@
 //! 屏幕取词模块
 //!
-//! 使用 UI Automation API 轮询获取选中文本。
+//! 轮询获取选中文本：Windows 使用 UI Automation API，macOS 使用
+//! Accessibility API，其余平台（如 Linux）通过监控系统剪贴板变化来近似
+//! 获取选中内容（用户需先复制）。三种实现统一通过 [`SelectionProvider`]
+//! trait 接入同一套轮询状态机。
 //! 当选中文本稳定 500ms 后显示气泡，文本变化或清空时关闭气泡。
 
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -8,14 +11,33 @@ use std::sync::Mutex;
 use std::thread;
 use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter, Manager, WebviewWindowBuilder, WebviewUrl};
+
+#[cfg(windows)]
 use windows::core::Interface;
-use windows::Win32::System::Com::{CoInitializeEx, CoUninitialize, COINIT_APARTMENTTHREADED};
+#[cfg(windows)]
+use windows::Win32::System::Com::{CoInitializeEx, COINIT_APARTMENTTHREADED};
+#[cfg(windows)]
 use windows::Win32::System::Ole::{
     SafeArrayAccessData, SafeArrayGetLBound, SafeArrayGetUBound, SafeArrayUnaccessData,
 };
+#[cfg(windows)]
 use windows::Win32::UI::Accessibility::{
-    CUIAutomation, IUIAutomation, IUIAutomationTextPattern, UIA_TextPatternId,
+    CUIAutomation, IUIAutomation, IUIAutomationElement, IUIAutomationTextPattern,
+    UIA_DocumentControlTypeId, UIA_EditControlTypeId, UIA_HyperlinkControlTypeId,
+    UIA_PaneControlTypeId, UIA_TextControlTypeId, UIA_TextPatternId,
+};
+#[cfg(windows)]
+use windows::Win32::Foundation::POINT;
+#[cfg(windows)]
+use windows::Win32::UI::Input::KeyboardAndMouse::{
+    SendInput, INPUT, INPUT_0, INPUT_KEYBOARD, KEYBDINPUT, KEYEVENTF_KEYUP, VIRTUAL_KEY, VK_CONTROL,
 };
+#[cfg(windows)]
+use windows::Win32::UI::WindowsAndMessaging::GetCursorPos;
+
+#[cfg(not(target_os = "macos"))]
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use serde::{Deserialize, Serialize};
 
 use crate::debug_log;
 
@@ -27,15 +49,40 @@ struct TextBounds {
     bottom: i32,
 }
 
+/// 平台相关的"获取当前选中文本"实现。各平台的轮询线程只需构造一个
+/// provider 并反复调用 `poll_selection`，状态机（稳定性检测、气泡生命周期）
+/// 统一由 [`run_polling_loop`] 负责，不随平台变化。
+trait SelectionProvider {
+    fn poll_selection(&self) -> Result<Option<(String, Option<TextBounds>)>, String>;
+}
+
+/// 屏幕取词相关配置（持久化于 `config.yaml` 的 `screen_capture` 节）
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ScreenCaptureConfig {
+    /// Windows 下，当 UIA TextPattern 不可用时（如 Chrome、Electron、部分
+    /// PDF 阅读器）是否允许模拟 Ctrl+C 读取剪贴板作为兜底。默认关闭，因为
+    /// 该方式会短暂侵入用户的剪贴板内容。
+    #[serde(default)]
+    pub clipboard_fallback: bool,
+}
+
 /// 全局状态：屏幕取词是否启用
 static SCREEN_CAPTURE_ENABLED: AtomicBool = AtomicBool::new(true);
 
+/// 全局状态：Windows 下 UIA TextPattern 不可用时是否允许剪贴板兜底
+#[cfg(windows)]
+static CLIPBOARD_FALLBACK_ENABLED: AtomicBool = AtomicBool::new(false);
+
 /// 全局 AppHandle
 static APP_HANDLE: Mutex<Option<AppHandle>> = Mutex::new(None);
 
 /// 当前显示的气泡单词
 static CURRENT_BUBBLE_WORD: Mutex<Option<String>> = Mutex::new(None);
 
+/// Linux 等平台：上一次观察到的剪贴板内容，用于检测变化
+#[cfg(not(any(windows, target_os = "macos")))]
+static LAST_CLIPBOARD_SNAPSHOT: Mutex<Option<String>> = Mutex::new(None);
+
 /// 启用/禁用屏幕取词
 pub fn set_enabled(enabled: bool) {
     SCREEN_CAPTURE_ENABLED.store(enabled, Ordering::SeqCst);
@@ -47,6 +94,12 @@ pub fn is_enabled() -> bool {
     SCREEN_CAPTURE_ENABLED.load(Ordering::SeqCst)
 }
 
+/// 获取气泡当前显示的单词（用于打开窗口时自动带入搜索）；
+/// 气泡关闭后返回 `None`，不保留更早取到的单词
+pub fn last_captured_word() -> Option<String> {
+    CURRENT_BUBBLE_WORD.lock().unwrap().clone()
+}
+
 /// 初始化屏幕取词
 pub fn init_screen_capture(app: &AppHandle) -> Result<(), String> {
     debug_log("Initializing screen capture with polling...");
@@ -57,6 +110,12 @@ pub fn init_screen_capture(app: &AppHandle) -> Result<(), String> {
         *handle = Some(app.clone());
     }
 
+    #[cfg(windows)]
+    {
+        let config = load_screen_capture_config(app);
+        CLIPBOARD_FALLBACK_ENABLED.store(config.clipboard_fallback, Ordering::SeqCst);
+    }
+
     // 启动轮询线程
     thread::spawn(|| {
         if let Err(e) = start_polling() {
@@ -67,27 +126,206 @@ pub fn init_screen_capture(app: &AppHandle) -> Result<(), String> {
     Ok(())
 }
 
-/// 启动轮询
-fn start_polling() -> Result<(), String> {
-    debug_log("Starting selection polling...");
+/// 同一个焦点窗口两次剪贴板兜底尝试之间的最短间隔。避免焦点长时间停留在
+/// 终端、文件资源管理器等不支持 TextPattern 的窗口时，200ms 的轮询间隔把
+/// Ctrl+C 当成按键风暴发给前台进程（在终端里 Ctrl+C 等同 SIGINT）。
+#[cfg(windows)]
+const FALLBACK_RETRY_COOLDOWN: Duration = Duration::from_secs(5);
+
+/// Windows：通过 UI Automation 的 TextPattern 读取选中文本
+#[cfg(windows)]
+struct WindowsUiaProvider {
+    automation: IUIAutomation,
+    /// 上一次尝试剪贴板兜底的焦点窗口句柄及时间，用于节流
+    last_fallback_attempt: Mutex<Option<(isize, Instant)>>,
+}
 
-    unsafe {
-        // 初始化 COM
-        CoInitializeEx(None, COINIT_APARTMENTTHREADED)
-            .ok()
-            .map_err(|e| format!("CoInitializeEx failed: {:?}", e))?;
+#[cfg(windows)]
+impl WindowsUiaProvider {
+    /// 初始化 COM 并创建（复用的）IUIAutomation 实例
+    fn new() -> Result<Self, String> {
+        unsafe {
+            CoInitializeEx(None, COINIT_APARTMENTTHREADED)
+                .ok()
+                .map_err(|e| format!("CoInitializeEx failed: {:?}", e))?;
+        }
+
+        let automation: IUIAutomation = unsafe {
+            windows::Win32::System::Com::CoCreateInstance(
+                &CUIAutomation,
+                None,
+                windows::Win32::System::Com::CLSCTX_INPROC_SERVER,
+            )
+            .map_err(|e| format!("Failed to create IUIAutomation: {:?}", e))?
+        };
+
+        Ok(Self {
+            automation,
+            last_fallback_attempt: Mutex::new(None),
+        })
+    }
+
+    /// 是否允许对 `hwnd` 尝试一次剪贴板兜底：同一个窗口在冷却时间内只尝试一次，
+    /// 防止对同一个不支持 TextPattern 的焦点窗口反复模拟 Ctrl+C
+    fn should_attempt_fallback(&self, hwnd: isize) -> bool {
+        let mut last = self.last_fallback_attempt.lock().unwrap();
+        let now = Instant::now();
+        if let Some((last_hwnd, last_time)) = *last {
+            if last_hwnd == hwnd && now.duration_since(last_time) < FALLBACK_RETRY_COOLDOWN {
+                return false;
+            }
+        }
+        *last = Some((hwnd, now));
+        true
+    }
+}
+
+#[cfg(windows)]
+impl SelectionProvider for WindowsUiaProvider {
+    fn poll_selection(&self) -> Result<Option<(String, Option<TextBounds>)>, String> {
+        match get_selected_text_with_automation(&self.automation) {
+            Ok(result) => Ok(result),
+            Err(e) if CLIPBOARD_FALLBACK_ENABLED.load(Ordering::SeqCst) => {
+                let Some(focused) = get_focused_element(&self.automation) else {
+                    return Err(e);
+                };
+
+                // 只在焦点控件看起来像文本内容时才模拟 Ctrl+C，跳过终端、
+                // 文件资源管理器、任务栏、对话框按钮等与文本无关的控件
+                if !is_text_like_control(&focused) {
+                    return Err(e);
+                }
+
+                let hwnd = unsafe { focused.CurrentNativeWindowHandle() }
+                    .map(|h| h.0 as isize)
+                    .unwrap_or(0);
+
+                if !self.should_attempt_fallback(hwnd) {
+                    return Err(e);
+                }
+
+                debug_log(&format!(
+                    "[ScreenCapture] UIA TextPattern unavailable ({}), trying clipboard fallback",
+                    e
+                ));
+                get_selected_text_via_clipboard_copy()
+            }
+            Err(e) => Err(e),
+        }
     }
+}
 
-    // 创建 UI Automation 实例（复用，避免每次轮询都创建）
-    let automation: IUIAutomation = unsafe {
-        windows::Win32::System::Com::CoCreateInstance(
-            &CUIAutomation,
-            None,
-            windows::Win32::System::Com::CLSCTX_INPROC_SERVER,
-        )
-        .map_err(|e| format!("Failed to create IUIAutomation: {:?}", e))?
+/// 获取当前焦点元素，失败时返回 `None` 而不是向上传播错误（兜底路径是尽力而为）
+#[cfg(windows)]
+fn get_focused_element(automation: &IUIAutomation) -> Option<IUIAutomationElement> {
+    unsafe { automation.GetFocusedElement().ok() }
+}
+
+/// 判断焦点控件是否像文本内容：Chrome、Electron 等应用的富文本区域通常
+/// 报告为 Document/Pane，原生输入框报告为 Edit/Text，链接为 Hyperlink。
+/// 终端、资源管理器、任务栏等控件类型不在此列，不做剪贴板兜底。
+#[cfg(windows)]
+fn is_text_like_control(element: &IUIAutomationElement) -> bool {
+    let Ok(control_type) = (unsafe { element.CurrentControlType() }) else {
+        return false;
     };
 
+    matches!(
+        control_type,
+        UIA_DocumentControlTypeId
+            | UIA_EditControlTypeId
+            | UIA_TextControlTypeId
+            | UIA_HyperlinkControlTypeId
+            | UIA_PaneControlTypeId
+    )
+}
+
+/// 从 `config.yaml` 的 `screen_capture` 节加载配置，缺失或解析失败时使用默认值
+#[cfg(windows)]
+fn load_screen_capture_config(app: &AppHandle) -> ScreenCaptureConfig {
+    let config_path = match crate::llm::resolve_config_path(app) {
+        Ok(path) => path,
+        Err(e) => {
+            debug_log(&format!(
+                "[ScreenCapture] Failed to resolve config path: {}",
+                e
+            ));
+            return ScreenCaptureConfig::default();
+        }
+    };
+
+    match crate::llm::load_config_file(&config_path) {
+        Ok(config_file) => config_file.screen_capture.unwrap_or_default(),
+        Err(e) => {
+            debug_log(&format!(
+                "[ScreenCapture] Failed to load config, using defaults: {}",
+                e
+            ));
+            ScreenCaptureConfig::default()
+        }
+    }
+}
+
+/// macOS：通过 Accessibility API 读取选中文本
+#[cfg(target_os = "macos")]
+struct MacAccessibilityProvider;
+
+#[cfg(target_os = "macos")]
+impl SelectionProvider for MacAccessibilityProvider {
+    fn poll_selection(&self) -> Result<Option<(String, Option<TextBounds>)>, String> {
+        get_selected_text_via_accessibility()
+    }
+}
+
+/// Linux（以及其他非 Windows/macOS 平台）：通过检测剪贴板内容变化来近似获取选中文本
+#[cfg(not(any(windows, target_os = "macos")))]
+struct ClipboardSelectionProvider;
+
+#[cfg(not(any(windows, target_os = "macos")))]
+impl SelectionProvider for ClipboardSelectionProvider {
+    fn poll_selection(&self) -> Result<Option<(String, Option<TextBounds>)>, String> {
+        get_selected_text_via_clipboard()
+    }
+}
+
+/// 启动轮询（Windows：UI Automation）
+#[cfg(windows)]
+fn start_polling() -> Result<(), String> {
+    debug_log("Starting selection polling (Windows UI Automation)...");
+
+    let provider = WindowsUiaProvider::new()?;
+    run_polling_loop(move || provider.poll_selection());
+
+    Ok(())
+}
+
+/// 启动轮询（macOS：Accessibility API）
+#[cfg(target_os = "macos")]
+fn start_polling() -> Result<(), String> {
+    debug_log("Starting selection polling (macOS Accessibility API)...");
+
+    let provider = MacAccessibilityProvider;
+    run_polling_loop(move || provider.poll_selection());
+
+    Ok(())
+}
+
+/// 启动轮询（Linux 等：剪贴板变化）
+#[cfg(not(any(windows, target_os = "macos")))]
+fn start_polling() -> Result<(), String> {
+    debug_log("Starting selection polling (clipboard)...");
+
+    let provider = ClipboardSelectionProvider;
+    run_polling_loop(move || provider.poll_selection());
+
+    Ok(())
+}
+
+/// 轮询主循环：调用 `get_current` 获取当前选中文本，处理稳定性检测与气泡生命周期
+fn run_polling_loop<F>(get_current: F)
+where
+    F: Fn() -> Result<Option<(String, Option<TextBounds>)>, String>,
+{
     // 上次检测到的文本和时间
     let mut last_text: Option<String> = None;
     let mut last_text_time: Option<Instant> = None;
@@ -101,7 +339,7 @@ fn start_polling() -> Result<(), String> {
         }
 
         // 获取当前选中文本
-        let current = get_selected_text_with_automation(&automation);
+        let current = get_current();
 
         match current {
             Ok(Some((text, bounds))) => {
@@ -183,6 +421,7 @@ fn close_bubble() {
 }
 
 /// 使用 UI Automation 获取选中文本及其位置（复用 automation 实例）
+#[cfg(windows)]
 fn get_selected_text_with_automation(
     automation: &IUIAutomation,
 ) -> Result<Option<(String, Option<TextBounds>)>, String> {
@@ -238,6 +477,7 @@ fn get_selected_text_with_automation(
 }
 
 /// 从 IUIAutomationTextRange 获取边界矩形
+#[cfg(windows)]
 fn get_text_bounds(
     range: &windows::Win32::UI::Accessibility::IUIAutomationTextRange,
 ) -> Option<TextBounds> {
@@ -283,6 +523,320 @@ fn get_text_bounds(
     }
 }
 
+/// 模拟 Ctrl+C 后等待剪贴板更新的时间
+#[cfg(windows)]
+const CLIPBOARD_FALLBACK_WAIT: Duration = Duration::from_millis(100);
+
+/// UIA TextPattern 不可用时的兜底：快照剪贴板、模拟 Ctrl+C、读取新内容、
+/// 再把剪贴板恢复成用户原有的内容，避免悄悄清空/替换用户的复制缓冲区。
+/// 没有边界信息，定位改用当前鼠标位置。
+///
+/// 只能恢复文本内容——如果剪贴板当前装的是图片、文件等非文本内容，
+/// `read_text()` 读不出快照，事后也就无法恢复，所以这种情况下直接放弃
+/// 本次兜底，不模拟 Ctrl+C，不去覆盖那些我们恢复不了的内容。
+#[cfg(windows)]
+fn get_selected_text_via_clipboard_copy() -> Result<Option<(String, Option<TextBounds>)>, String> {
+    let app = {
+        let handle = APP_HANDLE.lock().unwrap();
+        handle.clone()
+    };
+    let Some(app) = app else {
+        return Ok(None);
+    };
+
+    let Some(original) = app.clipboard().read_text().ok() else {
+        return Ok(None);
+    };
+
+    send_ctrl_c()?;
+    thread::sleep(CLIPBOARD_FALLBACK_WAIT);
+
+    let copied = app.clipboard().read_text().ok();
+
+    // 恢复用户原有的剪贴板内容，不留痕迹
+    let _ = app.clipboard().write_text(original.clone());
+
+    let Some(copied) = copied else {
+        return Ok(None);
+    };
+
+    if copied == original {
+        // 剪贴板未变化，说明当前没有选中任何内容
+        return Ok(None);
+    }
+
+    Ok(Some((copied, get_cursor_bounds())))
+}
+
+/// 合成一次 Ctrl+C 按键：down VK_CONTROL, down 'C', up 'C', up VK_CONTROL
+#[cfg(windows)]
+fn send_ctrl_c() -> Result<(), String> {
+    const VK_C: VIRTUAL_KEY = VIRTUAL_KEY(0x43);
+
+    let key_input = |vk: VIRTUAL_KEY, key_up: bool| INPUT {
+        r#type: INPUT_KEYBOARD,
+        Anonymous: INPUT_0 {
+            ki: KEYBDINPUT {
+                wVk: vk,
+                wScan: 0,
+                dwFlags: if key_up { KEYEVENTF_KEYUP } else { Default::default() },
+                time: 0,
+                dwExtraInfo: 0,
+            },
+        },
+    };
+
+    let inputs = [
+        key_input(VK_CONTROL, false),
+        key_input(VK_C, false),
+        key_input(VK_C, true),
+        key_input(VK_CONTROL, true),
+    ];
+
+    let sent = unsafe { SendInput(&inputs, std::mem::size_of::<INPUT>() as i32) };
+    if sent as usize != inputs.len() {
+        return Err("SendInput did not deliver all synthesized key events".to_string());
+    }
+
+    Ok(())
+}
+
+/// 读取当前鼠标位置，作为剪贴板兜底路径下气泡定位的依据
+#[cfg(windows)]
+fn get_cursor_bounds() -> Option<TextBounds> {
+    let mut point = POINT::default();
+    unsafe { GetCursorPos(&mut point).ok()? };
+
+    Some(TextBounds {
+        left: point.x,
+        top: point.y,
+        right: point.x,
+        bottom: point.y,
+    })
+}
+
+/// macOS Accessibility API 的最小 FFI 绑定，只包含本模块需要的部分
+#[cfg(target_os = "macos")]
+mod mac_ax {
+    use std::ffi::{c_void, CStr, CString};
+    use std::os::raw::{c_char, c_int};
+
+    pub type CFTypeRef = *const c_void;
+    pub type AXUIElementRef = CFTypeRef;
+    pub type CFStringRef = CFTypeRef;
+    pub type AXError = c_int;
+
+    pub const AX_ERROR_SUCCESS: AXError = 0;
+    const CF_STRING_ENCODING_UTF8: u32 = 0x0800_0100;
+    /// `AXValueType.kAXValueCGRectType`
+    const AX_VALUE_CGRECT_TYPE: c_int = 3;
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct CGPoint {
+        x: f64,
+        y: f64,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct CGSize {
+        width: f64,
+        height: f64,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct CGRect {
+        origin: CGPoint,
+        size: CGSize,
+    }
+
+    #[link(name = "ApplicationServices", kind = "framework")]
+    extern "C" {
+        fn AXUIElementCreateSystemWide() -> AXUIElementRef;
+        fn AXUIElementCopyAttributeValue(
+            element: AXUIElementRef,
+            attribute: CFStringRef,
+            value: *mut CFTypeRef,
+        ) -> AXError;
+        fn AXUIElementCopyParameterizedAttributeValue(
+            element: AXUIElementRef,
+            attribute: CFStringRef,
+            parameter: CFTypeRef,
+            value: *mut CFTypeRef,
+        ) -> AXError;
+        fn AXValueGetValue(value: CFTypeRef, value_type: c_int, value_ptr: *mut c_void) -> bool;
+    }
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+        fn CFStringCreateWithCString(
+            alloc: CFTypeRef,
+            c_str: *const c_char,
+            encoding: u32,
+        ) -> CFStringRef;
+        fn CFStringGetLength(string: CFStringRef) -> isize;
+        fn CFStringGetCString(
+            string: CFStringRef,
+            buffer: *mut c_char,
+            buffer_size: isize,
+            encoding: u32,
+        ) -> bool;
+        fn CFRelease(cf: CFTypeRef);
+    }
+
+    fn cfstring(s: &str) -> CFStringRef {
+        let c_str = CString::new(s).unwrap();
+        unsafe { CFStringCreateWithCString(std::ptr::null(), c_str.as_ptr(), CF_STRING_ENCODING_UTF8) }
+    }
+
+    fn cfstring_to_string(cf: CFStringRef) -> Option<String> {
+        unsafe {
+            let len = CFStringGetLength(cf);
+            let capacity = len * 4 + 1;
+            let mut buf = vec![0 as c_char; capacity as usize];
+            if CFStringGetCString(cf, buf.as_mut_ptr(), capacity, CF_STRING_ENCODING_UTF8) {
+                CStr::from_ptr(buf.as_ptr()).to_str().ok().map(|s| s.to_string())
+            } else {
+                None
+            }
+        }
+    }
+
+    /// 读取系统当前焦点元素选中的文本及其边界（屏幕坐标，物理像素）
+    pub fn read_focused_selection() -> Option<(String, Option<super::TextBounds>)> {
+        unsafe {
+            let system_wide = AXUIElementCreateSystemWide();
+            if system_wide.is_null() {
+                return None;
+            }
+
+            let focused_attr = cfstring("AXFocusedUIElement");
+            let mut focused: CFTypeRef = std::ptr::null();
+            let err = AXUIElementCopyAttributeValue(system_wide, focused_attr, &mut focused);
+            CFRelease(focused_attr);
+            CFRelease(system_wide);
+
+            if err != AX_ERROR_SUCCESS || focused.is_null() {
+                return None;
+            }
+
+            let text_attr = cfstring("AXSelectedText");
+            let mut text_value: CFTypeRef = std::ptr::null();
+            let err = AXUIElementCopyAttributeValue(focused, text_attr, &mut text_value);
+            CFRelease(text_attr);
+
+            if err != AX_ERROR_SUCCESS || text_value.is_null() {
+                CFRelease(focused);
+                return None;
+            }
+
+            let text = cfstring_to_string(text_value);
+            CFRelease(text_value);
+
+            let text = match text {
+                Some(t) if !t.is_empty() => t,
+                _ => {
+                    CFRelease(focused);
+                    return None;
+                }
+            };
+
+            let bounds = read_selection_bounds(focused);
+            CFRelease(focused);
+
+            Some((text, bounds))
+        }
+    }
+
+    /// 读取 `AXSelectedTextRange` 对应的屏幕边界矩形（`AXBoundsForRange`）
+    unsafe fn read_selection_bounds(focused: AXUIElementRef) -> Option<super::TextBounds> {
+        let range_attr = cfstring("AXSelectedTextRange");
+        let mut range_value: CFTypeRef = std::ptr::null();
+        let err = AXUIElementCopyAttributeValue(focused, range_attr, &mut range_value);
+        CFRelease(range_attr);
+
+        if err != AX_ERROR_SUCCESS || range_value.is_null() {
+            return None;
+        }
+
+        let bounds_attr = cfstring("AXBoundsForRange");
+        let mut bounds_value: CFTypeRef = std::ptr::null();
+        let err = AXUIElementCopyParameterizedAttributeValue(
+            focused,
+            bounds_attr,
+            range_value,
+            &mut bounds_value,
+        );
+        CFRelease(bounds_attr);
+        CFRelease(range_value);
+
+        if err != AX_ERROR_SUCCESS || bounds_value.is_null() {
+            return None;
+        }
+
+        let mut rect = CGRect::default();
+        let ok = AXValueGetValue(
+            bounds_value,
+            AX_VALUE_CGRECT_TYPE,
+            &mut rect as *mut CGRect as *mut c_void,
+        );
+        CFRelease(bounds_value);
+
+        if !ok {
+            return None;
+        }
+
+        let left = rect.origin.x as i32;
+        let top = rect.origin.y as i32;
+        let width = rect.size.width as i32;
+        let height = rect.size.height as i32;
+
+        Some(super::TextBounds {
+            left,
+            top,
+            right: left + width,
+            bottom: top + height,
+        })
+    }
+}
+
+/// 使用 Accessibility API 获取焦点元素选中的文本及其边界
+#[cfg(target_os = "macos")]
+fn get_selected_text_via_accessibility() -> Result<Option<(String, Option<TextBounds>)>, String> {
+    Ok(mac_ax::read_focused_selection())
+}
+
+/// Linux 等平台：通过检测剪贴板内容变化来近似获取"选中文本"
+///
+/// 没有 UI Automation / Accessibility 这样的跨应用选区 API，因此退而求其次：
+/// 只要用户手动复制过文本且与上次看到的不同，就当作一次新的取词。没有边界信息。
+#[cfg(not(any(windows, target_os = "macos")))]
+fn get_selected_text_via_clipboard() -> Result<Option<(String, Option<TextBounds>)>, String> {
+    let app = {
+        let handle = APP_HANDLE.lock().unwrap();
+        handle.clone()
+    };
+
+    let Some(app) = app else {
+        return Ok(None);
+    };
+
+    let text = match app.clipboard().read_text() {
+        Ok(t) => t,
+        Err(_) => return Ok(None),
+    };
+
+    let mut last_seen = LAST_CLIPBOARD_SNAPSHOT.lock().unwrap();
+    if last_seen.as_deref() == Some(text.as_str()) {
+        return Ok(None);
+    }
+    *last_seen = Some(text.clone());
+
+    Ok(Some((text, None)))
+}
+
 /// 验证是否为有效单词
 fn is_valid_word(text: &str) -> bool {
     // 长度限制：1-50 字符
@@ -303,6 +857,19 @@ fn is_valid_word(text: &str) -> bool {
     text.chars().any(|c| c.is_ascii_alphabetic())
 }
 
+/// 在 `window` 能枚举到的所有显示器中，找到物理坐标 `(x, y)` 所在的那一个
+fn find_monitor_at(window: &tauri::WebviewWindow, x: i32, y: i32) -> Option<tauri::Monitor> {
+    let monitors = window.available_monitors().ok()?;
+    monitors.into_iter().find(|m| {
+        let pos = m.position();
+        let size = m.size();
+        x >= pos.x
+            && x < pos.x + size.width as i32
+            && y >= pos.y
+            && y < pos.y + size.height as i32
+    })
+}
+
 /// 显示气泡窗口
 fn show_bubble(word: &str, bounds: Option<TextBounds>) {
     let app = {
@@ -329,25 +896,33 @@ fn show_bubble(word: &str, bounds: Option<TextBounds>) {
     }
 
     let word = word.to_string();
-    let bounds_data = bounds.map(|b| (b.left, b.bottom));
+    // 物理像素：文本左上角（用于定位所在显示器）及气泡锚点（文本下方）
+    let bounds_data = bounds.map(|b| (b.left, b.top, b.bottom));
     let app_clone = app.clone();
 
     let _ = app.run_on_main_thread(move || {
-        // 获取主窗口用于获取显示器信息
+        // 获取主窗口，仅作为枚举显示器、创建气泡窗口的句柄来源
         let main_window = match app_clone.get_webview_window("main") {
             Some(w) => w,
             None => return,
         };
 
-        // 获取 DPI 缩放因子
-        let scale_factor = main_window.scale_factor().unwrap_or(1.0);
+        // 选中文本可能和主窗口不在同一块显示器上，且混合 DPI 环境下各显示器
+        // 缩放比例可能不同，因此按文本的物理坐标定位它实际所在的显示器，
+        // 而不是直接使用主窗口所在的显示器
+        let monitor = bounds_data
+            .and_then(|(left, top, _)| find_monitor_at(&main_window, left, top))
+            .or_else(|| main_window.current_monitor().ok().flatten());
+
+        // 使用目标显示器自身的缩放因子，而非主窗口所在显示器的
+        let scale_factor = monitor.as_ref().map(|m| m.scale_factor()).unwrap_or(1.0);
 
         // 气泡尺寸
         let bubble_width = 320.0;
         let bubble_height = 150.0;
 
-        // 计算气泡位置
-        let (text_x, text_y) = if let Some((left, bottom)) = bounds_data {
+        // 计算气泡位置（逻辑像素，桌面全局坐标系）
+        let (text_x, text_y) = if let Some((left, _, bottom)) = bounds_data {
             (
                 (left as f64 / scale_factor) as i32,
                 ((bottom + 5) as f64 / scale_factor) as i32,
@@ -356,36 +931,40 @@ fn show_bubble(word: &str, bounds: Option<TextBounds>) {
             (100, 100) // 默认位置
         };
 
-        // 获取屏幕尺寸（逻辑像素）
-        let (screen_width, screen_height) = main_window
-            .current_monitor()
-            .ok()
-            .flatten()
+        // 目标显示器的逻辑边界（全局坐标系），用于将气泡限制在该显示器内
+        let (monitor_left, monitor_top, monitor_right, monitor_bottom) = monitor
+            .as_ref()
             .map(|m| {
+                let pos = m.position();
                 let size = m.size();
+                let left = (pos.x as f64 / scale_factor) as i32;
+                let top = (pos.y as f64 / scale_factor) as i32;
                 (
-                    (size.width as f64 / scale_factor) as i32,
-                    (size.height as f64 / scale_factor) as i32,
+                    left,
+                    top,
+                    left + (size.width as f64 / scale_factor) as i32,
+                    top + (size.height as f64 / scale_factor) as i32,
                 )
             })
-            .unwrap_or((1920, 1080));
+            .unwrap_or((0, 0, 1920, 1080));
 
         // 计算气泡位置，默认在文本下方 10px
         let mut bubble_x = text_x;
         let mut bubble_y = text_y + 10;
 
-        // 检查边界
-        if bubble_x + bubble_width as i32 > screen_width {
-            bubble_x = screen_width - bubble_width as i32 - 10;
+        // 检查边界（相对于文本所在显示器的整块逻辑区域，而非整个桌面；
+        // 按显示器完整尺寸计算，不扣除任务栏等占用的部分）
+        if bubble_x + bubble_width as i32 > monitor_right {
+            bubble_x = monitor_right - bubble_width as i32 - 10;
         }
-        if bubble_x < 10 {
-            bubble_x = 10;
+        if bubble_x < monitor_left + 10 {
+            bubble_x = monitor_left + 10;
         }
-        if bubble_y + bubble_height as i32 > screen_height {
+        if bubble_y + bubble_height as i32 > monitor_bottom {
             bubble_y = text_y - bubble_height as i32 - 30;
         }
-        if bubble_y < 10 {
-            bubble_y = 10;
+        if bubble_y < monitor_top + 10 {
+            bubble_y = monitor_top + 10;
         }
 
         let url = format!("/bubble?word={}", urlencoding::encode(&word));
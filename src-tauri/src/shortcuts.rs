@@ -1,56 +1,175 @@
 //! Global shortcut implementation for Dictyy
 //!
-//! Handles Ctrl+` shortcut to toggle window visibility.
+//! Loads a `shortcuts` section from `config.yaml` mapping named actions to
+//! shortcut strings, and registers each one to the matching handler.
 
-use tauri::{AppHandle, Emitter, Manager, Runtime};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
 
-/// Default shortcut key
+/// Default shortcut for toggling window visibility (kept for backwards compatibility)
 pub const DEFAULT_SHORTCUT: &str = "Ctrl+`";
 
-/// Setup global shortcuts
+fn default_toggle_window() -> String {
+    DEFAULT_SHORTCUT.to_string()
+}
+fn default_screen_capture_toggle() -> String {
+    "Ctrl+Shift+S".to_string()
+}
+fn default_copy_result() -> String {
+    "Ctrl+Shift+C".to_string()
+}
+fn default_quit() -> String {
+    "Ctrl+Shift+Q".to_string()
+}
+
+/// Named shortcut actions loaded from (and persisted to) `config.yaml`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShortcutsConfig {
+    #[serde(default = "default_toggle_window")]
+    pub toggle_window: String,
+    #[serde(default = "default_screen_capture_toggle")]
+    pub screen_capture_toggle: String,
+    #[serde(default = "default_copy_result")]
+    pub copy_result: String,
+    #[serde(default = "default_quit")]
+    pub quit: String,
+}
+
+impl Default for ShortcutsConfig {
+    fn default() -> Self {
+        Self {
+            toggle_window: default_toggle_window(),
+            screen_capture_toggle: default_screen_capture_toggle(),
+            copy_result: default_copy_result(),
+            quit: default_quit(),
+        }
+    }
+}
+
+impl ShortcutsConfig {
+    /// Named (action, shortcut string) pairs, in registration order
+    fn actions(&self) -> [(&'static str, &str); 4] {
+        [
+            ("toggle_window", &self.toggle_window),
+            ("screen_capture_toggle", &self.screen_capture_toggle),
+            ("copy_result", &self.copy_result),
+            ("quit", &self.quit),
+        ]
+    }
+}
+
+/// Load the `shortcuts` section of `config.yaml`, falling back to defaults
+/// when the file is missing, unparsable, or the section is absent.
+fn load_shortcuts_config(app: &AppHandle) -> ShortcutsConfig {
+    let config_path = match crate::llm::resolve_config_path(app) {
+        Ok(path) => path,
+        Err(e) => {
+            crate::debug_log(&format!("[Shortcuts] Failed to resolve config path: {}", e));
+            return ShortcutsConfig::default();
+        }
+    };
+
+    match crate::llm::load_config_file(&config_path) {
+        Ok(config_file) => config_file.shortcuts.unwrap_or_default(),
+        Err(e) => {
+            crate::debug_log(&format!("[Shortcuts] Failed to load config, using defaults: {}", e));
+            ShortcutsConfig::default()
+        }
+    }
+}
+
+/// Register `shortcuts` against their handlers.
 ///
-/// # Arguments
-/// * `app` - Tauri app handle
-/// * `shortcut_str` - Shortcut string (e.g., "Ctrl+`")
-/// * `enabled` - Whether to enable the shortcut
-#[tauri::command]
-pub async fn setup_shortcuts<R: Runtime>(
-    app: AppHandle<R>,
-    shortcut_str: String,
-    enabled: bool,
-) -> Result<(), String> {
-    let shortcuts = app.global_shortcut();
-
-    // Unregister all existing shortcuts first
-    shortcuts
+/// Unparsable shortcut strings are skipped (and logged) since they can't be
+/// registered at all, but two actions bound to the *same* chord is a
+/// reportable conflict, not a log-and-continue situation: registering one
+/// and silently dropping the other would let the caller believe both took
+/// effect. So conflicts are validated for up front, before anything is
+/// (un)registered, and turned into an `Err` the caller can surface.
+fn register_all(app: &AppHandle, shortcuts: &ShortcutsConfig) -> Result<(), String> {
+    let manager = app.global_shortcut();
+
+    let mut to_register: Vec<(&'static str, Shortcut)> = Vec::new();
+
+    for (action, shortcut_str) in shortcuts.actions() {
+        if shortcut_str.trim().is_empty() {
+            continue;
+        }
+
+        let shortcut: Shortcut = match shortcut_str.parse() {
+            Ok(s) => s,
+            Err(e) => {
+                crate::debug_log(&format!(
+                    "[Shortcuts] Failed to parse '{}' for {}: {}",
+                    shortcut_str, action, e
+                ));
+                continue;
+            }
+        };
+
+        if let Some((conflicting_action, _)) = to_register.iter().find(|(_, s)| *s == shortcut) {
+            return Err(format!(
+                "Shortcut '{}' is assigned to both '{}' and '{}'",
+                shortcut_str, conflicting_action, action
+            ));
+        }
+
+        to_register.push((action, shortcut));
+    }
+
+    manager
         .unregister_all()
         .map_err(|e| format!("Failed to unregister shortcuts: {}", e))?;
 
-    if !enabled {
-        return Ok(());
+    for (action, shortcut) in to_register {
+        let app_handle = app.clone();
+        let action_name = action.to_string();
+        manager
+            .on_shortcut(shortcut.clone(), move |_app, _shortcut, event| {
+                if event.state == ShortcutState::Pressed {
+                    dispatch_action(&app_handle, &action_name);
+                }
+            })
+            .map_err(|e| format!("Failed to register shortcut '{}': {}", action, e))?;
     }
 
-    // Parse shortcut
-    let shortcut: Shortcut = shortcut_str
-        .parse()
-        .map_err(|e| format!("Failed to parse shortcut '{}': {}", shortcut_str, e))?;
-
-    // Register shortcut
-    let app_handle = app.clone();
-    shortcuts
-        .on_shortcut(shortcut, move |_app, _shortcut, event| {
-            if event.state == ShortcutState::Pressed {
-                toggle_window(&app_handle);
-            }
-        })
-        .map_err(|e| format!("Failed to register shortcut: {}", e))?;
+    Ok(())
+}
+
+/// Run the handler bound to a named action
+fn dispatch_action(app: &AppHandle, action: &str) {
+    match action {
+        "toggle_window" => toggle_window(app),
+        "screen_capture_toggle" => {
+            let new_state = !crate::screen_capture::is_enabled();
+            crate::screen_capture::set_enabled(new_state);
+        }
+        "copy_result" => {
+            // 实际复制内容由前端发起（只有它知道当前渲染的释义文本）
+            let _ = app.emit("request-copy-result", ());
+        }
+        "quit" => app.exit(0),
+        _ => {}
+    }
+}
+
+/// Tauri command: update shortcuts from the frontend, validating for
+/// conflicts, re-registering them, and persisting the change to `config.yaml`.
+#[tauri::command]
+pub async fn setup_shortcuts(app: AppHandle, shortcuts: ShortcutsConfig) -> Result<(), String> {
+    register_all(&app, &shortcuts)?;
+
+    let config_path = crate::llm::resolve_config_path(&app)?;
+    let mut config_file = crate::llm::load_config_file(&config_path)?;
+    config_file.shortcuts = Some(shortcuts);
+    crate::llm::write_config_file(&config_path, &config_file)?;
 
     Ok(())
 }
 
 /// Toggle window visibility
-fn toggle_window<R: Runtime>(app: &AppHandle<R>) {
+fn toggle_window(app: &AppHandle) {
     if let Some(window) = app.get_webview_window("main") {
         if window.is_visible().unwrap_or(false) {
             let _ = window.hide();
@@ -58,28 +177,14 @@ fn toggle_window<R: Runtime>(app: &AppHandle<R>) {
             let _ = window.show();
             let _ = window.unminimize();
             let _ = window.set_focus();
-            // Notify frontend to focus input
-            let _ = app.emit("new-query", ());
+            // Notify frontend to focus input, carrying the last grabbed word (if any)
+            let _ = app.emit("new-query", crate::screen_capture::last_captured_word());
         }
     }
 }
 
-/// Initialize default shortcut on app startup
-pub fn init_shortcuts<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
-    let shortcuts = app.global_shortcut();
-
-    let shortcut: Shortcut = DEFAULT_SHORTCUT
-        .parse()
-        .map_err(|e| format!("Failed to parse default shortcut: {}", e))?;
-
-    let app_handle = app.clone();
-    shortcuts
-        .on_shortcut(shortcut, move |_app, _shortcut, event| {
-            if event.state == ShortcutState::Pressed {
-                toggle_window(&app_handle);
-            }
-        })
-        .map_err(|e| format!("Failed to register default shortcut: {}", e))?;
-
-    Ok(())
+/// Initialize shortcuts on app startup, loading actions from `config.yaml`
+pub fn init_shortcuts(app: &AppHandle) -> Result<(), String> {
+    let shortcuts = load_shortcuts_config(app);
+    register_all(app, &shortcuts)
 }
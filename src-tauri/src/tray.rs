@@ -8,12 +8,12 @@ use tauri::{
     AppHandle, Emitter, Manager, Runtime,
 };
 
-#[cfg(windows)]
 use crate::screen_capture;
 
 /// Tray menu item IDs
 const MENU_ID_SHOW: &str = "tray-show";
 const MENU_ID_SCREEN_CAPTURE: &str = "tray-screen-capture";
+const MENU_ID_COPY_RESULT: &str = "tray-copy-result";
 const MENU_ID_EXIT: &str = "tray-exit";
 
 /// Initialize the system tray
@@ -22,7 +22,6 @@ pub fn init_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::erro
     let show_item = MenuItem::with_id(app, MENU_ID_SHOW, "Show", true, None::<&str>)?;
 
     // Screen capture toggle (default enabled)
-    #[cfg(windows)]
     let screen_capture_item = CheckMenuItem::with_id(
         app,
         MENU_ID_SCREEN_CAPTURE,
@@ -32,15 +31,16 @@ pub fn init_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::erro
         None::<&str>,
     )?;
 
+    let copy_result_item = MenuItem::with_id(app, MENU_ID_COPY_RESULT, "复制释义", true, None::<&str>)?;
+
     let separator = PredefinedMenuItem::separator(app)?;
     let exit_item = MenuItem::with_id(app, MENU_ID_EXIT, "Exit", true, None::<&str>)?;
 
     // Build menu
-    #[cfg(windows)]
-    let menu = Menu::with_items(app, &[&show_item, &screen_capture_item, &separator, &exit_item])?;
-
-    #[cfg(not(windows))]
-    let menu = Menu::with_items(app, &[&show_item, &separator, &exit_item])?;
+    let menu = Menu::with_items(
+        app,
+        &[&show_item, &screen_capture_item, &copy_result_item, &separator, &exit_item],
+    )?;
 
     // Build tray icon
     let _tray = TrayIconBuilder::new()
@@ -51,12 +51,15 @@ pub fn init_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::erro
             MENU_ID_SHOW => {
                 show_and_focus(app);
             }
-            #[cfg(windows)]
             MENU_ID_SCREEN_CAPTURE => {
                 // Toggle screen capture
                 let new_state = !screen_capture::is_enabled();
                 screen_capture::set_enabled(new_state);
             }
+            MENU_ID_COPY_RESULT => {
+                // 实际复制内容由前端发起（只有它知道当前渲染的释义文本）
+                let _ = app.emit("request-copy-result", ());
+            }
             MENU_ID_EXIT => {
                 app.exit(0);
             }
@@ -78,12 +81,13 @@ pub fn init_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::erro
     Ok(())
 }
 
-/// Show window and focus input
+/// Show window and focus input, carrying the last grabbed word (if any) so
+/// the frontend can auto-search it
 fn show_and_focus<R: Runtime>(app: &AppHandle<R>) {
     if let Some(window) = app.get_webview_window("main") {
         let _ = window.show();
         let _ = window.unminimize();
         let _ = window.set_focus();
-        let _ = app.emit("new-query", ());
+        let _ = app.emit("new-query", screen_capture::last_captured_word());
     }
 }